@@ -0,0 +1,200 @@
+use nom::error::ErrorKind;
+use thiserror::Error;
+
+use graphile_worker_crontab_types::CrontabTimer;
+
+use crate::nom_crontab_timer::nom_crontab_timer;
+
+/// Field names in parse order, used to name the field a generic (non-boundary)
+/// parse failure occurred in, by counting field-separating spaces before it
+const FIELD_NAMES: [&str; 5] = ["minutes", "hours", "days", "months", "days-of-week"];
+
+/// `nom`'s error type for this crate's parsers. Boundary and range checks (in
+/// `nom_crontab_timer`) construct `detail` directly at the point of failure, so it
+/// carries a precise [`CrontabParseError`] instead of just a `nom::error::ErrorKind`.
+/// Falls back to `detail: None` for plain grammar failures (e.g. an unexpected
+/// character), which `parse_crontab_timer` turns into a position-aware error itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CrontabError<'a> {
+    pub(crate) input: &'a str,
+    pub(crate) detail: Option<CrontabParseError>,
+}
+
+impl<'a> CrontabError<'a> {
+    pub(crate) fn detailed(input: &'a str, detail: CrontabParseError) -> Self {
+        CrontabError {
+            input,
+            detail: Some(detail),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for CrontabError<'a> {
+    fn from_error_kind(input: &'a str, _kind: ErrorKind) -> Self {
+        CrontabError {
+            input,
+            detail: None,
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+/// A crontab timer string failed to parse. Reports which of the five fields is at
+/// fault, the offending value, and (where applicable) the field's valid range.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CrontabParseError {
+    #[error("{field}: value {value} out of range {min}-{max}")]
+    OutOfRange {
+        field: &'static str,
+        value: u32,
+        min: u32,
+        max: u32,
+    },
+    #[error("{field}: range start {left} is not less than end {right}")]
+    InvalidRange {
+        field: &'static str,
+        left: u32,
+        right: u32,
+    },
+    #[error("{field}: unexpected '{found}' at offset {offset}")]
+    UnexpectedCharacter {
+        field: &'static str,
+        found: char,
+        offset: usize,
+    },
+    #[error("{field}: unexpected end of input")]
+    UnexpectedEnd { field: &'static str },
+}
+
+/// Parses a five-field crontab timer string (or an `@`-macro), returning a
+/// [`CrontabParseError`] that names the offending field when parsing fails, rather
+/// than an opaque `nom` error.
+pub fn parse_crontab_timer(input: &str) -> Result<CrontabTimer, CrontabParseError> {
+    match nom_crontab_timer(input) {
+        Ok((_, timer)) => Ok(timer),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+            Err(e.detail.unwrap_or_else(|| generic_error(input, e.input)))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(CrontabParseError::UnexpectedEnd {
+            field: FIELD_NAMES[FIELD_NAMES.len() - 1],
+        }),
+    }
+}
+
+/// Builds a position-aware error for a plain grammar failure (no richer detail was
+/// attached at the point of failure), by locating which field `remainder` - the
+/// real, still-unconsumed suffix of `input` that `nom` failed on - falls in.
+fn generic_error(input: &str, remainder: &str) -> CrontabParseError {
+    let offset = input.len() - remainder.len();
+    let field_idx = input[..offset]
+        .bytes()
+        .filter(|&b| b == b' ')
+        .count()
+        .min(FIELD_NAMES.len() - 1);
+    let field = FIELD_NAMES[field_idx];
+
+    match remainder.chars().next() {
+        Some(found) => CrontabParseError::UnexpectedCharacter {
+            field,
+            found,
+            offset,
+        },
+        None => CrontabParseError::UnexpectedEnd { field },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_crontab_timer_accepts_valid_input() {
+        assert!(parse_crontab_timer("* * * * *").is_ok());
+    }
+
+    #[test]
+    fn parse_crontab_timer_accepts_names_and_macros() {
+        assert!(parse_crontab_timer("0 0 * JAN-MAR MON-FRI").is_ok());
+        assert!(parse_crontab_timer("@daily").is_ok());
+    }
+
+    #[test]
+    fn parse_crontab_timer_reports_out_of_range_hour() {
+        let err = parse_crontab_timer("0 26 * * *").unwrap_err();
+        assert_eq!(
+            err,
+            CrontabParseError::OutOfRange {
+                field: "hours",
+                value: 26,
+                min: 0,
+                max: 23,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_crontab_timer_reports_invalid_range_order() {
+        let err = parse_crontab_timer("30-10 * * * *").unwrap_err();
+        assert_eq!(
+            err,
+            CrontabParseError::InvalidRange {
+                field: "minutes",
+                left: 30,
+                right: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_crontab_timer_reports_unexpected_character() {
+        let err = parse_crontab_timer("* * * * !").unwrap_err();
+        assert_eq!(
+            err,
+            CrontabParseError::UnexpectedCharacter {
+                field: "days-of-week",
+                found: '!',
+                offset: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_crontab_timer_reports_missing_field() {
+        // Input ends right after the "days" field's value, before the space that
+        // would separate it from "months" - so "days" is the field in progress
+        // when input runs out, not "months".
+        let err = parse_crontab_timer("* * *").unwrap_err();
+        assert_eq!(err, CrontabParseError::UnexpectedEnd { field: "days" });
+    }
+
+    #[test]
+    fn parse_crontab_timer_points_at_the_actual_offending_character() {
+        // "5" alone is a perfectly valid minute; the parser only chokes once it
+        // reaches the '!' that should have been a field separator or comma.
+        let err = parse_crontab_timer("5! * * * *").unwrap_err();
+        assert_eq!(
+            err,
+            CrontabParseError::UnexpectedCharacter {
+                field: "minutes",
+                found: '!',
+                offset: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_crontab_timer_points_at_the_actual_offending_character_after_wildcard() {
+        let err = parse_crontab_timer("*x * * * *").unwrap_err();
+        assert_eq!(
+            err,
+            CrontabParseError::UnexpectedCharacter {
+                field: "minutes",
+                found: 'x',
+                offset: 1,
+            }
+        );
+    }
+}
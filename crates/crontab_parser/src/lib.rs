@@ -0,0 +1,4 @@
+mod error;
+mod nom_crontab_timer;
+
+pub use error::{parse_crontab_timer, CrontabParseError};
@@ -1,14 +1,86 @@
 use nom::{
     branch::alt,
-    character::complete::{self, char},
-    combinator::{map, opt, verify},
+    bytes::complete::{tag, tag_no_case},
+    character::complete::{self, char, satisfy},
+    combinator::{map, not, opt},
+    error::{ErrorKind, ParseError},
     multi::separated_list1,
-    sequence::{preceded, separated_pair, terminated},
-    IResult, Parser,
+    sequence::{preceded, terminated},
+    Err as NomErr, Parser,
 };
 
 use graphile_worker_crontab_types::{CrontabTimer, CrontabValue};
 
+use crate::error::{CrontabError, CrontabParseError};
+
+/// Result type for this module's combinators: same shape as `nom::IResult`, but
+/// fixed to our own error type so boundary and range failures can carry a precise
+/// [`CrontabParseError`] instead of an opaque `nom` error kind.
+type PResult<'a, O> = Result<(&'a str, O), NomErr<CrontabError<'a>>>;
+
+/// Wraps a parser so it only matches when not immediately followed by another
+/// alphanumeric character - e.g. so the macro tag `@hourly` doesn't match as a
+/// prefix of `@hourlyweird`, and the name `MON` doesn't match as a prefix of
+/// `MONDAY`.
+fn word_boundary<'a, O>(
+    mut parser: impl Parser<&'a str, O, CrontabError<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, O> {
+    move |input| {
+        let (rest, value) = parser.parse(input)?;
+        let (rest, _) = not(satisfy(|c: char| c.is_alphanumeric())).parse(rest)?;
+        Ok((rest, value))
+    }
+}
+
+/// Attempts to parse one of the well-known `@`-prefixed schedule macros
+/// (e.g. `@hourly`, `@daily`) and expands it to the equivalent `CrontabTimer`.
+fn crontab_macro(input: &str) -> PResult<'_, CrontabTimer> {
+    alt((
+        map(
+            word_boundary(alt((tag("@yearly"), tag("@annually")))),
+            |_| CrontabTimer {
+                minutes: vec![CrontabValue::Number(0)],
+                hours: vec![CrontabValue::Number(0)],
+                days: vec![CrontabValue::Number(1)],
+                months: vec![CrontabValue::Number(1)],
+                dows: vec![CrontabValue::Any],
+            },
+        ),
+        map(word_boundary(tag("@monthly")), |_| CrontabTimer {
+            minutes: vec![CrontabValue::Number(0)],
+            hours: vec![CrontabValue::Number(0)],
+            days: vec![CrontabValue::Number(1)],
+            months: vec![CrontabValue::Any],
+            dows: vec![CrontabValue::Any],
+        }),
+        map(word_boundary(tag("@weekly")), |_| CrontabTimer {
+            minutes: vec![CrontabValue::Number(0)],
+            hours: vec![CrontabValue::Number(0)],
+            days: vec![CrontabValue::Any],
+            months: vec![CrontabValue::Any],
+            dows: vec![CrontabValue::Number(0)],
+        }),
+        map(
+            word_boundary(alt((tag("@daily"), tag("@midnight")))),
+            |_| CrontabTimer {
+                minutes: vec![CrontabValue::Number(0)],
+                hours: vec![CrontabValue::Number(0)],
+                days: vec![CrontabValue::Any],
+                months: vec![CrontabValue::Any],
+                dows: vec![CrontabValue::Any],
+            },
+        ),
+        map(word_boundary(tag("@hourly")), |_| CrontabTimer {
+            minutes: vec![CrontabValue::Number(0)],
+            hours: vec![CrontabValue::Any],
+            days: vec![CrontabValue::Any],
+            months: vec![CrontabValue::Any],
+            dows: vec![CrontabValue::Any],
+        }),
+    ))
+    .parse(input)
+}
+
 #[derive(Debug, PartialEq, Eq)]
 enum CrontabPart {
     Minute,
@@ -28,40 +100,202 @@ impl CrontabPart {
             CrontabPart::DaysOfWeek => (0, 6),
         }
     }
+
+    /// Name used to identify this field in a [`CrontabParseError`]
+    fn field_name(&self) -> &'static str {
+        match self {
+            CrontabPart::Minute => "minutes",
+            CrontabPart::Hours => "hours",
+            CrontabPart::Days => "days",
+            CrontabPart::Months => "months",
+            CrontabPart::DaysOfWeek => "days-of-week",
+        }
+    }
+}
+
+/// Matches a case-insensitive three-letter month name (JAN..DEC) to its 1-12 number.
+/// Requires a word boundary afterwards, so e.g. "MARCH" isn't silently read as "MAR"
+/// with "CH" left dangling.
+fn month_name(input: &str) -> PResult<'_, u32> {
+    word_boundary(alt((
+        map(tag_no_case("JAN"), |_| 1),
+        map(tag_no_case("FEB"), |_| 2),
+        map(tag_no_case("MAR"), |_| 3),
+        map(tag_no_case("APR"), |_| 4),
+        map(tag_no_case("MAY"), |_| 5),
+        map(tag_no_case("JUN"), |_| 6),
+        map(tag_no_case("JUL"), |_| 7),
+        map(tag_no_case("AUG"), |_| 8),
+        map(tag_no_case("SEP"), |_| 9),
+        map(tag_no_case("OCT"), |_| 10),
+        map(tag_no_case("NOV"), |_| 11),
+        map(tag_no_case("DEC"), |_| 12),
+    )))
+    .parse(input)
+}
+
+/// Matches a case-insensitive three-letter day-of-week name (SUN..SAT) to its 0-6
+/// number. Requires a word boundary afterwards, so e.g. "MONDAY" isn't silently read
+/// as "MON" with "DAY" left dangling.
+fn day_of_week_name(input: &str) -> PResult<'_, u32> {
+    word_boundary(alt((
+        map(tag_no_case("SUN"), |_| 0),
+        map(tag_no_case("MON"), |_| 1),
+        map(tag_no_case("TUE"), |_| 2),
+        map(tag_no_case("WED"), |_| 3),
+        map(tag_no_case("THU"), |_| 4),
+        map(tag_no_case("FRI"), |_| 5),
+        map(tag_no_case("SAT"), |_| 6),
+    )))
+    .parse(input)
 }
 
-/// Attempts to parse a number with crontab part boundaries
-fn crontab_number<'a>(part: &CrontabPart) -> impl Fn(&'a str) -> IResult<&'a str, u32> {
+/// A name-lookup parser for one of the crontab parts that accepts names
+type NameLookup = for<'a> fn(&'a str) -> PResult<'a, u32>;
+
+/// Attempts to parse a number with crontab part boundaries, falling back to the
+/// part's name lookup (month or day-of-week abbreviations) when it has one. A
+/// value that parses but falls outside the field's boundaries fails with a
+/// [`CrontabParseError::OutOfRange`] naming this field, rather than an opaque `nom`
+/// error, so callers of `parse_crontab_timer` get an actionable message.
+fn crontab_number<'a>(part: &CrontabPart) -> impl Fn(&'a str) -> PResult<'a, u32> {
     let (min, max) = part.boundaries();
-    move |input| verify(complete::u32, |v| v >= &min && v <= &max).parse(input)
+    let field = part.field_name();
+    let name_lookup: Option<NameLookup> = match part {
+        CrontabPart::Months => Some(month_name),
+        CrontabPart::DaysOfWeek => Some(day_of_week_name),
+        _ => None,
+    };
+    move |input| {
+        if let Some(name_parser) = name_lookup {
+            if let Ok(result) = name_parser(input) {
+                return Ok(result);
+            }
+        }
+        match complete::u32::<&str, CrontabError>(input) {
+            Ok((rest, value)) if value >= min && value <= max => Ok((rest, value)),
+            Ok((_, value)) => Err(NomErr::Error(CrontabError::detailed(
+                input,
+                CrontabParseError::OutOfRange {
+                    field,
+                    value,
+                    min,
+                    max,
+                },
+            ))),
+            Err(_) => Err(NomErr::Error(CrontabError::from_error_kind(
+                input,
+                ErrorKind::Digit,
+            ))),
+        }
+    }
+}
+
+/// Promotes a recoverable `nom::Err::Error` into an unrecoverable `nom::Err::Failure`.
+/// Used once a preceding token (`-` or `/`) has committed a parse to one specific
+/// grammar production, so `alt` stops silently backtracking into a weaker
+/// interpretation (e.g. re-reading `30-10` as the bare number `30`) and the detailed
+/// error actually reaches `parse_crontab_timer`.
+fn commit<O>(result: PResult<'_, O>) -> PResult<'_, O> {
+    result.map_err(|err| match err {
+        NomErr::Error(e) => NomErr::Failure(e),
+        other => other,
+    })
 }
 
-/// Attempts to parse a range with crontab part boundaries
+/// Attempts to parse a range with crontab part boundaries. Once the `-` has been
+/// seen, the input can only be a range, so any further problem - an out-of-range
+/// right-hand side or an inverted range (e.g. `30-10`) - fails with a
+/// [`nom::Err::Failure`] carrying a [`CrontabParseError`], rather than letting `alt`
+/// fall back to treating the left-hand number alone as the value.
 fn crontab_range<'a, 'p>(
     part: &'p CrontabPart,
-) -> impl Fn(&'a str) -> IResult<&'a str, (u32, u32)> + 'p {
+) -> impl Fn(&'a str) -> PResult<'a, (u32, u32)> + 'p {
+    let field = part.field_name();
+    move |input| {
+        let (rest, left) = crontab_number(part)(input)?;
+        let (rest, _) = char('-').parse(rest)?;
+        let (rest, right) = commit(crontab_number(part)(rest))?;
+        if left < right {
+            Ok((rest, (left, right)))
+        } else {
+            Err(NomErr::Failure(CrontabError::detailed(
+                input,
+                CrontabParseError::InvalidRange { field, left, right },
+            )))
+        }
+    }
+}
+
+/// Attempts to parse a step divisor (the `n` in `*/n` or `a/n`), which must be at
+/// least 1 - a step of 0 would make field expansion loop forever. Out-of-range
+/// steps fail with a [`CrontabParseError::OutOfRange`], the same as any other
+/// boundary violation in this field.
+fn crontab_step_value<'a>(part: &CrontabPart) -> impl Fn(&'a str) -> PResult<'a, u32> {
+    let (_, max) = part.boundaries();
+    let field = part.field_name();
+    move |input| match complete::u32::<&str, CrontabError>(input) {
+        Ok((rest, value)) if (1..=max).contains(&value) => Ok((rest, value)),
+        Ok((_, value)) => Err(NomErr::Error(CrontabError::detailed(
+            input,
+            CrontabParseError::OutOfRange {
+                field,
+                value,
+                min: 1,
+                max,
+            },
+        ))),
+        Err(_) => Err(NomErr::Error(CrontabError::from_error_kind(
+            input,
+            ErrorKind::Digit,
+        ))),
+    }
+}
+
+/// Attempts to parse a step with crontab part boundaries. Once the `/` has been
+/// seen, a bad divisor is committed as a [`nom::Err::Failure`], so `opt` does not
+/// discard its [`CrontabParseError`] and read the `*` alone.
+fn crontab_wildcard<'a, 'p>(
+    part: &'p CrontabPart,
+) -> impl Fn(&'a str) -> PResult<'a, Option<u32>> + 'p {
     |input| {
-        verify(
-            separated_pair(crontab_number(part), char('-'), crontab_number(part)),
-            |(left, right)| left < right,
+        preceded(
+            char('*'),
+            opt(preceded(char('/'), |rest: &'a str| {
+                commit(crontab_step_value(part)(rest))
+            })),
         )
         .parse(input)
     }
 }
 
-/// Attempts to parse a step with crontab part boundaries
-fn crontab_wildcard<'a, 'p>(
+/// Attempts to parse a range-with-step (`1-59/2`) or a from-step (`10/3`, meaning
+/// `10-<max>/3`) with crontab part boundaries. Once the `/` has been seen, the input
+/// can only be a step expression, so a bad divisor is committed as a
+/// [`nom::Err::Failure`] rather than letting `alt` fall back to the base value alone.
+fn crontab_step_range<'a, 'p>(
     part: &'p CrontabPart,
-) -> impl Fn(&'a str) -> IResult<&'a str, Option<u32>> + 'p {
-    |input| preceded(char('*'), opt(preceded(char('/'), crontab_number(part)))).parse(input)
+) -> impl Fn(&'a str) -> PResult<'a, CrontabValue> + 'p {
+    |input| {
+        let (input, (start, end)) = alt((
+            map(crontab_range(part), |(left, right)| (left, Some(right))),
+            map(crontab_number(part), |n| (n, None)),
+        ))
+        .parse(input)?;
+        let (input, _) = char('/').parse(input)?;
+        let (input, step) = commit(crontab_step_value(part)(input))?;
+
+        Ok((input, CrontabValue::StepRange { start, end, step }))
+    }
 }
 
 /// Attempts to parse a crontab part
 fn crontab_value<'a, 'p>(
     part: &'p CrontabPart,
-) -> impl Fn(&'a str) -> IResult<&'a str, CrontabValue> + 'p {
+) -> impl Fn(&'a str) -> PResult<'a, CrontabValue> + 'p {
     |input| {
         alt((
+            crontab_step_range(part),
             map(crontab_range(part), |(left, right)| {
                 CrontabValue::Range(left, right)
             }),
@@ -78,12 +312,16 @@ fn crontab_value<'a, 'p>(
 /// Attempts to parse comma separated crontab values
 fn crontab_values<'a, 'p>(
     part: &'p CrontabPart,
-) -> impl Fn(&'a str) -> IResult<&'a str, Vec<CrontabValue>> + 'p {
+) -> impl Fn(&'a str) -> PResult<'a, Vec<CrontabValue>> + 'p {
     |input| separated_list1(char(','), crontab_value(part)).parse(input)
 }
 
-/// Parse all 5 crontab values
-pub(crate) fn nom_crontab_timer(input: &str) -> IResult<&str, CrontabTimer> {
+/// Parse all 5 crontab values, or a single `@`-prefixed schedule macro
+pub(crate) fn nom_crontab_timer(input: &str) -> PResult<'_, CrontabTimer> {
+    if let Ok((input, timer)) = crontab_macro(input) {
+        return Ok((input, timer));
+    }
+
     let (input, minutes) =
         terminated(crontab_values(&CrontabPart::Minute), char(' ')).parse(input)?;
     let (input, hours) = terminated(crontab_values(&CrontabPart::Hours), char(' ')).parse(input)?;
@@ -151,4 +389,89 @@ mod tests {
         let timer_result = nom_crontab_timer("*/7!,8,30-35 * 3,*/4 * *,4 bar");
         assert!(timer_result.is_err());
     }
+
+    #[test]
+    fn crontab_timer_test_month_and_dow_names() {
+        assert_eq!(
+            Ok((
+                " foo",
+                CrontabTimer {
+                    minutes: vec![CrontabValue::Any],
+                    hours: vec![CrontabValue::Any],
+                    days: vec![CrontabValue::Any],
+                    months: vec![CrontabValue::Range(1, 3), CrontabValue::Number(12)],
+                    dows: vec![CrontabValue::Range(1, 5)],
+                }
+            )),
+            nom_crontab_timer("* * * JAN-mar,DEC MON-FRI foo"),
+        );
+    }
+
+    #[test]
+    fn crontab_timer_test_full_name_is_not_read_as_an_abbreviation() {
+        // "MONDAY" must not be silently read as "MON" with "DAY" left dangling.
+        assert!(nom_crontab_timer("0 0 * * MONDAY").is_err());
+    }
+
+    #[test]
+    fn crontab_timer_test_range_with_step() {
+        assert_eq!(
+            Ok((
+                " foo",
+                CrontabTimer {
+                    minutes: vec![CrontabValue::StepRange {
+                        start: 1,
+                        end: Some(59),
+                        step: 2
+                    }],
+                    hours: vec![CrontabValue::Any],
+                    days: vec![CrontabValue::StepRange {
+                        start: 10,
+                        end: None,
+                        step: 3
+                    }],
+                    months: vec![CrontabValue::Any],
+                    dows: vec![CrontabValue::Any],
+                }
+            )),
+            nom_crontab_timer("1-59/2 * 10/3 * * foo"),
+        );
+    }
+
+    #[test]
+    fn crontab_timer_test_step_range_rejects_zero_step() {
+        let timer_result = nom_crontab_timer("10/0 * * * * foo");
+        assert!(timer_result.is_err());
+    }
+
+    #[test]
+    fn crontab_timer_test_wildcard_rejects_zero_step() {
+        let timer_result = nom_crontab_timer("*/0 * * * * foo");
+        assert!(timer_result.is_err());
+    }
+
+    #[test]
+    fn crontab_timer_test_macros() {
+        let cases = [
+            ("@yearly foo", "0 0 1 1 *"),
+            ("@annually foo", "0 0 1 1 *"),
+            ("@monthly foo", "0 0 1 * *"),
+            ("@weekly foo", "0 0 * * 0"),
+            ("@daily foo", "0 0 * * *"),
+            ("@midnight foo", "0 0 * * *"),
+            ("@hourly foo", "0 * * * *"),
+        ];
+
+        for (macro_input, equivalent) in cases {
+            let (_, expected) = nom_crontab_timer(&format!("{equivalent} foo")).unwrap();
+            assert_eq!(Ok((" foo", expected)), nom_crontab_timer(macro_input));
+        }
+    }
+
+    #[test]
+    fn crontab_timer_test_misspelled_macro_is_not_read_as_a_known_one() {
+        // "@hourlyweird" must not be silently read as "@hourly" with "weird" left
+        // dangling - it isn't a known macro, nor a valid 5-field timer.
+        assert!(nom_crontab_timer("@hourlyweird bar").is_err());
+    }
 }
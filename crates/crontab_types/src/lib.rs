@@ -0,0 +1,35 @@
+mod scheduler;
+
+pub use scheduler::CrontabTimerIter;
+
+/// A single value within one field of a crontab timer (minute, hour, day, month or
+/// day-of-week), before it has been expanded into the concrete integers it matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrontabValue {
+    /// `*`: matches every value in the field's range
+    Any,
+    /// A single value, e.g. `5`
+    Number(u32),
+    /// An inclusive range, e.g. `1-5`
+    Range(u32, u32),
+    /// `*/n`: every `n`th value starting at the field's minimum
+    Step(u32),
+    /// A stepped range, e.g. `1-59/2` or `10/3`. `end` is `None` only when the
+    /// field's maximum should be used, e.g. `10/3` meaning `10-<max>/3`.
+    StepRange {
+        start: u32,
+        end: Option<u32>,
+        step: u32,
+    },
+}
+
+/// The five fields of a parsed crontab timer: minutes, hours, days, months and
+/// days-of-week. Each field is a list of values that are OR'd together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrontabTimer {
+    pub minutes: Vec<CrontabValue>,
+    pub hours: Vec<CrontabValue>,
+    pub days: Vec<CrontabValue>,
+    pub months: Vec<CrontabValue>,
+    pub dows: Vec<CrontabValue>,
+}
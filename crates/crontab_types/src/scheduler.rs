@@ -0,0 +1,253 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+
+use crate::{CrontabTimer, CrontabValue};
+
+/// How far into the future `next_after` is willing to search before giving up and
+/// returning `None`. Handles timers that can never match (e.g. `31` as the only day
+/// for a month that never has 31 days, combined with a month that does have 31 days
+/// never occurring alongside it).
+const MAX_SEARCH: Duration = Duration::days(366 * 5);
+
+impl CrontabTimer {
+    /// Computes the next time strictly after `after` that this timer fires, or
+    /// `None` if no match is found within the search window.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let minutes = expand_field(&self.minutes, 0, 59);
+        let hours = expand_field(&self.hours, 0, 23);
+        let days = expand_field(&self.days, 1, 31);
+        let months = expand_field(&self.months, 1, 12);
+        let dows = expand_field(&self.dows, 0, 6);
+
+        let day_of_month_restricted = !self.days.contains(&CrontabValue::Any);
+        let day_of_week_restricted = !self.dows.contains(&CrontabValue::Any);
+
+        let mut candidate = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let deadline = after + MAX_SEARCH;
+
+        while candidate < deadline {
+            if !months.contains(&candidate.month()) {
+                candidate = next_month(candidate)?;
+                continue;
+            }
+
+            let day_matches = match (day_of_month_restricted, day_of_week_restricted) {
+                (true, true) => {
+                    days.contains(&candidate.day())
+                        || dows.contains(&candidate.weekday().num_days_from_sunday())
+                }
+                (true, false) => days.contains(&candidate.day()),
+                (false, true) => dows.contains(&candidate.weekday().num_days_from_sunday()),
+                (false, false) => true,
+            };
+            if !day_matches {
+                candidate = next_day(candidate)?;
+                continue;
+            }
+
+            if !hours.contains(&candidate.hour()) {
+                candidate = next_hour(candidate)?;
+                continue;
+            }
+
+            if !minutes.contains(&candidate.minute()) {
+                candidate += Duration::minutes(1);
+                continue;
+            }
+
+            return Some(candidate);
+        }
+
+        None
+    }
+
+    /// Returns an iterator yielding successive times this timer fires, starting
+    /// strictly after `after`.
+    pub fn iter_after(&self, after: DateTime<Utc>) -> CrontabTimerIter<'_> {
+        CrontabTimerIter {
+            timer: self,
+            cursor: after,
+        }
+    }
+}
+
+/// Iterator over successive fire times of a [`CrontabTimer`], produced by
+/// [`CrontabTimer::iter_after`].
+pub struct CrontabTimerIter<'a> {
+    timer: &'a CrontabTimer,
+    cursor: DateTime<Utc>,
+}
+
+impl Iterator for CrontabTimerIter<'_> {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<DateTime<Utc>> {
+        let next = self.timer.next_after(self.cursor)?;
+        self.cursor = next;
+        Some(next)
+    }
+}
+
+/// Expands a field's values into the sorted, deduplicated set of concrete integers
+/// it matches within `[min, max]`.
+fn expand_field(values: &[CrontabValue], min: u32, max: u32) -> BTreeSet<u32> {
+    let mut expanded = BTreeSet::new();
+    for value in values {
+        match value {
+            CrontabValue::Any => expanded.extend(min..=max),
+            CrontabValue::Number(n) => {
+                expanded.insert(*n);
+            }
+            CrontabValue::Range(a, b) => expanded.extend(*a..=*b),
+            CrontabValue::Step(step) => {
+                let mut v = min;
+                while v <= max {
+                    expanded.insert(v);
+                    v += step;
+                }
+            }
+            CrontabValue::StepRange { start, end, step } => {
+                let mut v = *start;
+                let end = end.unwrap_or(max);
+                while v <= end {
+                    expanded.insert(v);
+                    v += step;
+                }
+            }
+        }
+    }
+    expanded
+}
+
+/// Jumps to the first instant of the next hour, discarding minutes.
+fn next_hour(dt: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    Some(dt.with_minute(0)?.with_second(0)? + Duration::hours(1))
+}
+
+/// Jumps to the first instant of the next day, discarding hours and minutes.
+fn next_day(dt: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    Some(dt.with_hour(0)?.with_minute(0)?.with_second(0)? + Duration::days(1))
+}
+
+/// Jumps to the first instant of the next month, discarding day, hour and minute.
+/// Handled explicitly (rather than via `Duration`) so it is correct regardless of
+/// the current month's length.
+fn next_month(dt: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let (year, month) = if dt.month() == 12 {
+        (dt.year() + 1, 1)
+    } else {
+        (dt.year(), dt.month() + 1)
+    };
+    Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(minutes: &[CrontabValue], hours: &[CrontabValue], days: &[CrontabValue], months: &[CrontabValue], dows: &[CrontabValue]) -> CrontabTimer {
+        CrontabTimer {
+            minutes: minutes.to_vec(),
+            hours: hours.to_vec(),
+            days: days.to_vec(),
+            months: months.to_vec(),
+            dows: dows.to_vec(),
+        }
+    }
+
+    #[test]
+    fn next_after_every_minute() {
+        let t = timer(
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+        );
+        let after = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 15).unwrap();
+        let next = t.next_after(after).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2024, 3, 1, 12, 31, 0).unwrap());
+    }
+
+    #[test]
+    fn next_after_specific_time() {
+        // "30 4 * * *" - every day at 04:30
+        let t = timer(
+            &[CrontabValue::Number(30)],
+            &[CrontabValue::Number(4)],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+        );
+        let after = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let next = t.next_after(after).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2024, 3, 2, 4, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn next_after_leap_day() {
+        // "0 0 29 2 *" - only matches on a leap day
+        let t = timer(
+            &[CrontabValue::Number(0)],
+            &[CrontabValue::Number(0)],
+            &[CrontabValue::Number(29)],
+            &[CrontabValue::Number(2)],
+            &[CrontabValue::Any],
+        );
+        let after = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+        let next = t.next_after(after).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn next_after_day_of_month_or_day_of_week() {
+        // "0 0 1 * MON" - midnight on the 1st of the month OR any Monday
+        let t = timer(
+            &[CrontabValue::Number(0)],
+            &[CrontabValue::Number(0)],
+            &[CrontabValue::Number(1)],
+            &[CrontabValue::Any],
+            &[CrontabValue::Number(1)],
+        );
+        let after = Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap();
+        let next = t.next_after(after).unwrap();
+        // 2024-03-04 is a Monday, which comes before the 1st of April
+        assert_eq!(next, Utc.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn next_after_impossible_timer_returns_none() {
+        // "0 0 31 2 *" - February never has 31 days
+        let t = timer(
+            &[CrontabValue::Number(0)],
+            &[CrontabValue::Number(0)],
+            &[CrontabValue::Number(31)],
+            &[CrontabValue::Number(2)],
+            &[CrontabValue::Any],
+        );
+        let after = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(t.next_after(after), None);
+    }
+
+    #[test]
+    fn iter_after_yields_successive_times() {
+        let t = timer(
+            &[CrontabValue::Number(0)],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+            &[CrontabValue::Any],
+        );
+        let after = Utc.with_ymd_and_hms(2024, 3, 1, 0, 15, 0).unwrap();
+        let times: Vec<_> = t.iter_after(after).take(3).collect();
+        assert_eq!(
+            times,
+            vec![
+                Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 3, 1, 2, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 3, 1, 3, 0, 0).unwrap(),
+            ]
+        );
+    }
+}